@@ -0,0 +1,252 @@
+// Fans a single proxy out to multiple backend MCP servers: merges their
+// tool lists under a namespaced `<backend_id>__<tool>` name and routes
+// `tools/call` back to the owning backend, mirroring how a connection
+// manager tolerates one dead upstream instead of failing the whole list.
+
+use anyhow::{Context, Result};
+use reqwest::Client;
+
+use crate::clients::{NamedPipeMcpClient, ReadyGate, StdioMcpClient, TlsOptions, WebSocketMcpClient};
+use crate::config::BackendConfig;
+use crate::http;
+use crate::protocol::MCPRequest;
+use crate::spawn_notification_forwarder;
+
+const NAMESPACE_SEPARATOR: &str = "__";
+
+enum BackendClient {
+    Http { client: Client, base_url: String },
+    Stdio(StdioMcpClient),
+    NamedPipe(NamedPipeMcpClient),
+    WebSocket(WebSocketMcpClient),
+}
+
+impl BackendClient {
+    async fn connect(
+        cfg: &BackendConfig,
+        http_client: &Client,
+        tls: &TlsOptions,
+        ready_gate: Option<&ReadyGate>,
+    ) -> Result<Self> {
+        if let Some(url) = &cfg.url {
+            if url.starts_with("ws://") || url.starts_with("wss://") {
+                let mut client = WebSocketMcpClient::connect(url, tls).await?;
+                spawn_notification_forwarder(client.take_notifications());
+                return Ok(BackendClient::WebSocket(client));
+            }
+            return Ok(BackendClient::Http {
+                client: http_client.clone(),
+                base_url: url.clone(),
+            });
+        }
+
+        if let Some(command) = &cfg.command {
+            let args_str = cfg.args.as_deref().unwrap_or("");
+            let args: Vec<String> = if args_str.is_empty() {
+                Vec::new()
+            } else {
+                args_str.split_whitespace().map(|s| s.to_string()).collect()
+            };
+            let mut client = StdioMcpClient::new(command, &args, ready_gate.cloned()).await?;
+            spawn_notification_forwarder(client.take_notifications());
+            return Ok(BackendClient::Stdio(client));
+        }
+
+        if let Some(pipe) = &cfg.pipe {
+            let mut client = NamedPipeMcpClient::connect(pipe).await?;
+            spawn_notification_forwarder(client.take_notifications());
+            return Ok(BackendClient::NamedPipe(client));
+        }
+
+        Err(anyhow::anyhow!(
+            "Backend '{}' must specify one of url, command, or pipe",
+            cfg.id
+        ))
+    }
+
+    async fn call(&mut self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
+        match self {
+            BackendClient::Http { client, base_url } => {
+                http::proxy_mcp_request_http(
+                    client,
+                    base_url,
+                    MCPRequest {
+                        method: method.to_string(),
+                        params,
+                    },
+                )
+                .await
+            }
+            BackendClient::Stdio(c) => c.call(method, Some(params)).await,
+            BackendClient::NamedPipe(c) => c.call(method, Some(params)).await,
+            BackendClient::WebSocket(c) => c.call(method, Some(params)).await,
+        }
+    }
+}
+
+/// Aggregates tool lists and calls across every backend that connected
+/// successfully. A backend that fails to connect or answer is reported to
+/// stderr and otherwise skipped, so one dead upstream doesn't take down the
+/// whole proxy.
+pub struct Aggregator {
+    backends: Vec<(String, BackendClient)>,
+}
+
+impl Aggregator {
+    /// `ready_gate` is applied to every `command`-based backend, mirroring
+    /// the single-backend `--ready-pattern`/`--ready-timeout` flags (there's
+    /// no per-backend config for it yet).
+    pub async fn connect(
+        configs: &[BackendConfig],
+        http_client: &Client,
+        tls: &TlsOptions,
+        ready_gate: Option<&ReadyGate>,
+    ) -> Result<Self> {
+        let mut backends = Vec::new();
+        for cfg in configs {
+            match BackendClient::connect(cfg, http_client, tls, ready_gate).await {
+                Ok(client) => backends.push((cfg.id.clone(), client)),
+                Err(e) => eprintln!("[!] Failed to connect to backend '{}': {}", cfg.id, e),
+            }
+        }
+
+        if backends.is_empty() {
+            return Err(anyhow::anyhow!("No backends could be connected"));
+        }
+
+        Ok(Aggregator { backends })
+    }
+
+    pub fn backend_count(&self) -> usize {
+        self.backends.len()
+    }
+
+    /// Fan out `tools/list` to every backend and merge the results, with
+    /// each tool's name prefixed as `<backend_id>__<tool>`. A backend that
+    /// fails is logged and its tools are simply omitted.
+    pub async fn list_tools(&mut self) -> serde_json::Value {
+        let mut tools = Vec::new();
+
+        for (id, client) in &mut self.backends {
+            let result = client
+                .call("tools/list", serde_json::Value::Object(serde_json::Map::new()))
+                .await;
+
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("[!] Backend '{}' failed to list tools: {}", id, e);
+                    continue;
+                }
+            };
+
+            tools.extend(namespaced_tools_from_list_result(id, result));
+        }
+
+        serde_json::json!({ "tools": tools })
+    }
+
+    /// Route a `tools/call` whose `params.name` is namespaced as
+    /// `<backend_id>__<tool>` to the owning backend, stripping the prefix
+    /// back out before forwarding.
+    pub async fn call_tool(&mut self, mut params: serde_json::Value) -> Result<serde_json::Value> {
+        let namespaced_name = params
+            .get("name")
+            .and_then(|n| n.as_str())
+            .context("tools/call params must include a 'name' field")?
+            .to_string();
+
+        let (backend_id, tool_name) = split_namespaced_tool_name(&namespaced_name)?;
+
+        let (_, client) = self
+            .backends
+            .iter_mut()
+            .find(|(id, _)| id == backend_id)
+            .with_context(|| format!("Unknown backend '{}'", backend_id))?;
+
+        if let Some(name_field) = params.get_mut("name") {
+            *name_field = serde_json::Value::String(tool_name.to_string());
+        }
+
+        client.call("tools/call", params).await
+    }
+}
+
+/// Prefix a backend's own tool name so it can be routed back after merging,
+/// e.g. `learn` + `search` -> `learn__search`.
+fn namespace_tool_name(backend_id: &str, tool_name: &str) -> String {
+    format!("{backend_id}{NAMESPACE_SEPARATOR}{tool_name}")
+}
+
+/// Split a namespaced `<backend_id>__<tool>` name back into its parts, the
+/// inverse of [`namespace_tool_name`].
+fn split_namespaced_tool_name(namespaced_name: &str) -> Result<(&str, &str)> {
+    namespaced_name.split_once(NAMESPACE_SEPARATOR).with_context(|| {
+        format!(
+            "Tool name '{}' is not namespaced as '<backend>{}<tool>'",
+            namespaced_name, NAMESPACE_SEPARATOR
+        )
+    })
+}
+
+/// Pull the `tools` array out of one backend's raw `tools/list` response
+/// (tolerating both a bare result and an `{ "result": { "tools": [...] } }`
+/// envelope) and namespace each tool's name. A response with no `tools`
+/// array yields no tools, the same way a backend-call error does in
+/// [`Aggregator::list_tools`].
+fn namespaced_tools_from_list_result(backend_id: &str, result: serde_json::Value) -> Vec<serde_json::Value> {
+    let inner = result.get("result").cloned().unwrap_or(result);
+    let Some(backend_tools) = inner.get("tools").and_then(|t| t.as_array()) else {
+        return Vec::new();
+    };
+
+    backend_tools
+        .iter()
+        .map(|tool| {
+            let mut tool = tool.clone();
+            if let Some(name) = tool.get("name").and_then(|n| n.as_str()) {
+                tool["name"] = serde_json::Value::String(namespace_tool_name(backend_id, name));
+            }
+            tool
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespace_round_trips_through_split() {
+        let namespaced = namespace_tool_name("learn", "search");
+        assert_eq!(namespaced, "learn__search");
+
+        let (backend_id, tool_name) = split_namespaced_tool_name(&namespaced).unwrap();
+        assert_eq!(backend_id, "learn");
+        assert_eq!(tool_name, "search");
+    }
+
+    #[test]
+    fn split_namespaced_tool_name_rejects_an_unnamespaced_name() {
+        assert!(split_namespaced_tool_name("search").is_err());
+    }
+
+    #[test]
+    fn namespaced_tools_from_list_result_unwraps_and_prefixes() {
+        let result = serde_json::json!({
+            "result": { "tools": [{ "name": "search" }, { "name": "fetch" }] }
+        });
+
+        let tools = namespaced_tools_from_list_result("learn", result);
+
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0]["name"], "learn__search");
+        assert_eq!(tools[1]["name"], "learn__fetch");
+    }
+
+    #[test]
+    fn namespaced_tools_from_list_result_tolerates_missing_tools_array() {
+        let malformed = serde_json::json!({ "result": {} });
+        assert!(namespaced_tools_from_list_result("learn", malformed).is_empty());
+    }
+}
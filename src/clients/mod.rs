@@ -0,0 +1,11 @@
+// Backend MCP client implementations. Each client wraps a `Transport` over
+// whatever framed stream the backend speaks (child process stdio, a Unix
+// socket / named pipe, ...) and exposes a simple request/response `call`.
+
+pub mod named_pipe;
+pub mod stdio;
+pub mod websocket;
+
+pub use named_pipe::NamedPipeMcpClient;
+pub use stdio::{ReadyGate, StdioMcpClient};
+pub use websocket::{TlsOptions, WebSocketMcpClient};
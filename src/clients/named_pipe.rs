@@ -0,0 +1,140 @@
+use anyhow::{Context, Result};
+use tokio::io::BufReader as TokioBufReader;
+use tokio::sync::mpsc;
+
+use crate::transport::Transport;
+
+/// MCP backend reachable over a named pipe. On Windows, `\\.\pipe\...`
+/// paths are opened with the native named-pipe API; everywhere else, a Unix
+/// socket is tried first (the common case for MCP servers), falling back to
+/// a pair of FIFO handles.
+pub struct NamedPipeMcpClient {
+    transport: Transport,
+    /// Server-initiated messages (notifications, or requests with no
+    /// matching pending id) that arrived on this backend's stream.
+    pub notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+}
+
+/// Windows reserves this prefix for named pipes; anything else on a
+/// `--pipe` flag is treated as a Unix socket/FIFO path.
+fn is_windows_pipe_path(pipe_path: &str) -> bool {
+    pipe_path.starts_with(r"\\.\pipe\")
+}
+
+impl NamedPipeMcpClient {
+    pub async fn connect(pipe_path: &str) -> Result<Self> {
+        if is_windows_pipe_path(pipe_path) {
+            #[cfg(windows)]
+            return Self::connect_windows(pipe_path).await;
+            #[cfg(not(windows))]
+            return Err(anyhow::anyhow!(
+                "Path '{}' looks like a Windows named pipe, but this binary was not built for Windows",
+                pipe_path
+            ));
+        }
+
+        #[cfg(unix)]
+        return Self::connect_unix(pipe_path).await;
+        #[cfg(not(unix))]
+        Err(anyhow::anyhow!(
+            "Path '{}' is not a Windows named pipe and this binary was not built for Unix",
+            pipe_path
+        ))
+    }
+
+    #[cfg(unix)]
+    async fn connect_unix(pipe_path: &str) -> Result<Self> {
+        use tokio::fs::OpenOptions;
+        use tokio::net::UnixStream;
+
+        // Try opening as a Unix socket first (more common for MCP servers)
+        if let Ok(stream) = UnixStream::connect(pipe_path).await {
+            let (read_half, write_half) = tokio::io::split(stream);
+            let (transport, notifications) =
+                Transport::spawn(TokioBufReader::new(read_half), write_half);
+            return Ok(NamedPipeMcpClient {
+                transport,
+                notifications,
+            });
+        }
+
+        // Fallback to named pipe (FIFO) approach: separate read and write
+        // handles on the same path.
+        let write_file = OpenOptions::new()
+            .write(true)
+            .open(pipe_path)
+            .await
+            .with_context(|| format!("Failed to open named pipe for writing: {}", pipe_path))?;
+
+        let read_file = OpenOptions::new()
+            .read(true)
+            .open(pipe_path)
+            .await
+            .with_context(|| format!("Failed to open named pipe for reading: {}", pipe_path))?;
+
+        let (transport, notifications) =
+            Transport::spawn(TokioBufReader::new(read_file), write_file);
+
+        Ok(NamedPipeMcpClient {
+            transport,
+            notifications,
+        })
+    }
+
+    #[cfg(windows)]
+    async fn connect_windows(pipe_path: &str) -> Result<Self> {
+        use tokio::net::windows::named_pipe::ClientOptions;
+
+        // ERROR_PIPE_BUSY (winerror.h) - all instances of the pipe are busy
+        // servicing another client; the server will free one up shortly.
+        // This mirrors Windows' own WaitNamedPipe idiom, which also waits
+        // for a bounded period rather than forever.
+        const ERROR_PIPE_BUSY: i32 = 231;
+        const RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(50);
+        const CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+        let deadline = tokio::time::Instant::now() + CONNECT_TIMEOUT;
+        let client = loop {
+            match ClientOptions::new().open(pipe_path) {
+                Ok(client) => break client,
+                Err(e) if e.raw_os_error() == Some(ERROR_PIPE_BUSY) => {
+                    if tokio::time::Instant::now() >= deadline {
+                        return Err(anyhow::anyhow!(
+                            "Timed out after {:?} waiting for busy named pipe: {}",
+                            CONNECT_TIMEOUT,
+                            pipe_path
+                        ));
+                    }
+                    tokio::time::sleep(RETRY_DELAY).await;
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to open named pipe: {}", pipe_path));
+                }
+            }
+        };
+
+        let (read_half, write_half) = tokio::io::split(client);
+        let (transport, notifications) =
+            Transport::spawn(TokioBufReader::new(read_half), write_half);
+
+        Ok(NamedPipeMcpClient {
+            transport,
+            notifications,
+        })
+    }
+
+    pub async fn call(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        self.transport.send_request(method, params).await
+    }
+
+    /// Take ownership of the notification channel, e.g. to spawn a task
+    /// that forwards server-initiated messages to the calling client.
+    pub fn take_notifications(&mut self) -> mpsc::UnboundedReceiver<serde_json::Value> {
+        std::mem::replace(&mut self.notifications, mpsc::unbounded_channel().1)
+    }
+}
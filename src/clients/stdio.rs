@@ -0,0 +1,132 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tokio::io::{AsyncBufReadExt, BufReader as TokioBufReader};
+use tokio::process::{Child, Command as TokioCommand};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::transport::Transport;
+
+/// Gates the first outbound request on a readiness line appearing on the
+/// child's stderr, since many spawned MCP servers print a startup banner
+/// before they can accept JSON-RPC on stdin.
+#[derive(Debug, Clone)]
+pub struct ReadyGate {
+    /// Substring to look for in a stderr line. Once a line contains it, the
+    /// gate opens.
+    pub pattern: String,
+    /// Upper bound on how long to wait for `pattern` before opening the
+    /// gate anyway.
+    pub timeout: Duration,
+}
+
+/// MCP backend spawned as a child process, talking newline-delimited
+/// JSON-RPC over its stdin/stdout.
+pub struct StdioMcpClient {
+    // Kept alive so the child isn't reaped (and its stdio closed) while the
+    // transport tasks are still using it.
+    process: Child,
+    transport: Transport,
+    /// Server-initiated messages (notifications, or requests with no
+    /// matching pending id) that arrived on this backend's stream.
+    pub notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+    /// Resolves once it's safe to send the first request; consumed on the
+    /// first `call`.
+    ready: Option<oneshot::Receiver<()>>,
+}
+
+impl StdioMcpClient {
+    pub async fn new(command: &str, args: &[String], ready_gate: Option<ReadyGate>) -> Result<Self> {
+        let mut cmd = TokioCommand::new(command);
+        cmd.args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut process = cmd.spawn().context("Failed to spawn MCP server process")?;
+
+        let stdin = process.stdin.take().context("Failed to get stdin")?;
+        let stdout = process.stdout.take().context("Failed to get stdout")?;
+        let stdout = TokioBufReader::new(stdout);
+        let stderr = process.stderr.take().context("Failed to get stderr")?;
+
+        let ready = Some(spawn_stderr_reader(stderr, ready_gate));
+
+        let (transport, notifications) = Transport::spawn(stdout, stdin);
+
+        Ok(StdioMcpClient {
+            process,
+            transport,
+            notifications,
+            ready,
+        })
+    }
+
+    pub async fn call(
+        &mut self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        if let Some(ready) = self.ready.take() {
+            // Ignore a dropped sender: that just means the reader task
+            // ended (e.g. the child closed stderr) without ever opening
+            // the gate, which isn't worth failing the call over.
+            let _ = ready.await;
+        }
+        self.transport.send_request(method, params).await
+    }
+
+    /// Take ownership of the notification channel, e.g. to spawn a task
+    /// that forwards server-initiated messages to the calling client.
+    pub fn take_notifications(&mut self) -> mpsc::UnboundedReceiver<serde_json::Value> {
+        std::mem::replace(&mut self.notifications, mpsc::unbounded_channel().1)
+    }
+}
+
+/// Reads the child's stderr for as long as the process lives, forwarding
+/// each line to our own stderr so the banner is still visible to an
+/// operator. If `ready_gate` is set, the returned receiver resolves as soon
+/// as a line matches its pattern, or once its timeout elapses, whichever
+/// comes first; with no gate configured it resolves immediately.
+fn spawn_stderr_reader(
+    stderr: tokio::process::ChildStderr,
+    ready_gate: Option<ReadyGate>,
+) -> oneshot::Receiver<()> {
+    let (ready_tx, ready_rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let mut lines = TokioBufReader::new(stderr).lines();
+        let mut ready_tx = Some(ready_tx);
+
+        let Some(gate) = ready_gate else {
+            if let Some(tx) = ready_tx.take() {
+                let _ = tx.send(());
+            }
+            while let Ok(Some(line)) = lines.next_line().await {
+                eprintln!("[stderr] {}", line);
+            }
+            return;
+        };
+
+        let deadline = tokio::time::sleep(gate.timeout);
+        tokio::pin!(deadline);
+
+        loop {
+            tokio::select! {
+                line = lines.next_line() => {
+                    let Ok(Some(line)) = line else { break };
+                    eprintln!("[stderr] {}", line);
+                    if ready_tx.is_some() && line.contains(&gate.pattern) {
+                        let _ = ready_tx.take().unwrap().send(());
+                    }
+                }
+                _ = &mut deadline, if ready_tx.is_some() => {
+                    let _ = ready_tx.take().unwrap().send(());
+                }
+            }
+        }
+    });
+
+    ready_rx
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tokio_tungstenite::Connector;
+
+use crate::transport::Transport;
+
+/// TLS configuration for `wss://` backends.
+#[derive(Debug, Clone, Default)]
+pub struct TlsOptions {
+    /// Skip server certificate verification. Only ever meant for talking to
+    /// self-signed dev servers.
+    pub insecure: bool,
+    /// Path to a PEM-encoded CA bundle to trust, in addition to the
+    /// platform's root store.
+    pub ca_path: Option<String>,
+}
+
+/// MCP backend reachable over a persistent WebSocket connection. Each
+/// JSON-RPC message is sent/received as a single text frame, and the
+/// connection is held open for the lifetime of the process.
+pub struct WebSocketMcpClient {
+    transport: Transport,
+    /// Server-initiated messages (notifications, or requests with no
+    /// matching pending id) that arrived on this backend's stream.
+    pub notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+}
+
+impl WebSocketMcpClient {
+    pub async fn connect(url: &str, tls: &TlsOptions) -> Result<Self> {
+        let connector = if url.starts_with("wss://") {
+            Some(build_connector(tls)?)
+        } else {
+            None
+        };
+
+        let (ws_stream, _response) =
+            tokio_tungstenite::connect_async_tls_with_config(url, None, false, connector)
+                .await
+                .with_context(|| format!("Failed to connect to WebSocket MCP server: {}", url))?;
+
+        let (sink, stream) = ws_stream.split();
+
+        let outgoing = sink.with(|line: String| async move { Ok(Message::Text(line)) });
+        let incoming = stream.filter_map(|message| async move {
+            match message {
+                Ok(Message::Text(text)) => Some(Ok(text)),
+                Ok(Message::Close(_)) | Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => None,
+                Ok(Message::Binary(_)) | Ok(Message::Frame(_)) => None,
+                Err(e) => Some(Err(anyhow::Error::from(e))),
+            }
+        });
+
+        let (transport, notifications) = Transport::spawn_framed(incoming, outgoing);
+
+        Ok(WebSocketMcpClient {
+            transport,
+            notifications,
+        })
+    }
+
+    pub async fn call(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        self.transport.send_request(method, params).await
+    }
+
+    /// Take ownership of the notification channel, e.g. to spawn a task
+    /// that forwards server-initiated messages to the calling client.
+    pub fn take_notifications(&mut self) -> mpsc::UnboundedReceiver<serde_json::Value> {
+        std::mem::replace(&mut self.notifications, mpsc::unbounded_channel().1)
+    }
+}
+
+fn build_connector(tls: &TlsOptions) -> Result<Connector> {
+    use rustls::{ClientConfig, RootCertStore};
+
+    ensure_crypto_provider_installed();
+
+    let config = if tls.insecure {
+        ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(insecure::NoServerVerification))
+            .with_no_client_auth()
+    } else {
+        let mut roots = RootCertStore::empty();
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+        if let Some(ca_path) = &tls.ca_path {
+            let mut reader = std::io::BufReader::new(
+                std::fs::File::open(ca_path)
+                    .with_context(|| format!("Failed to open CA file: {}", ca_path))?,
+            );
+            for cert in rustls_pemfile::certs(&mut reader) {
+                roots
+                    .add(cert.context("Failed to parse CA certificate")?)
+                    .context("Failed to add CA certificate to root store")?;
+            }
+        }
+
+        ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth()
+    };
+
+    Ok(Connector::Rustls(Arc::new(config)))
+}
+
+/// `ClientConfig::builder()` panics if no process-level `CryptoProvider`
+/// has been installed. Install rustls's `ring` provider the first time we
+/// need one; later calls (or a provider installed elsewhere, e.g. by a
+/// dependency) are left alone.
+fn ensure_crypto_provider_installed() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    });
+}
+
+/// Certificate verifier that accepts anything, for `--tls-insecure`.
+mod insecure {
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+    use rustls::{DigitallySignedStruct, SignatureScheme};
+
+    #[derive(Debug)]
+    pub struct NoServerVerification;
+
+    impl ServerCertVerifier for NoServerVerification {
+        fn verify_server_cert(
+            &self,
+            _end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8],
+            _now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            Ok(ServerCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            vec![
+                SignatureScheme::RSA_PKCS1_SHA256,
+                SignatureScheme::ECDSA_NISTP256_SHA256,
+                SignatureScheme::RSA_PSS_SHA256,
+                SignatureScheme::ED25519,
+            ]
+        }
+    }
+}
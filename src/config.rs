@@ -0,0 +1,32 @@
+// Multi-backend configuration for `--config`, letting one proxy aggregate
+// several upstream MCP servers behind a single namespaced tool list.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// One upstream MCP server to aggregate. Exactly one of `url`, `command`, or
+/// `pipe` should be set, mirroring the single-backend CLI flags.
+#[derive(Deserialize, Clone)]
+pub struct BackendConfig {
+    /// Short identifier used to namespace this backend's tools, e.g. `learn`
+    /// turns a `search` tool into `learn__search`.
+    pub id: String,
+    pub url: Option<String>,
+    pub command: Option<String>,
+    pub args: Option<String>,
+    pub pipe: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ProxyConfig {
+    pub backends: Vec<BackendConfig>,
+}
+
+impl ProxyConfig {
+    pub fn load(path: &str) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read backend config: {}", path))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("Failed to parse backend config: {}", path))
+    }
+}
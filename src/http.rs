@@ -0,0 +1,185 @@
+// One-shot or streaming HTTP backend: each MCP call is a single POST, whose
+// response is either a plain JSON body or a Server-Sent Events stream.
+//
+// Long-running `tools/call` invocations can emit interim
+// `notifications/progress` messages before the final result arrives on the
+// same SSE stream; those are forwarded to stdout immediately so the calling
+// client sees progress, and the call only resolves once the message whose
+// `id` matches our request shows up.
+//
+// The returned message is the backend's raw JSON-RPC response; the caller
+// runs it through the interceptor chain (see `crate::interceptors`) before
+// extracting `result` and writing it back to the client.
+
+use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use reqwest::Client;
+
+use crate::protocol::{JsonRpcRequest, MCPRequest};
+
+pub async fn proxy_mcp_request_http(
+    client: &Client,
+    base_url: &str,
+    req: MCPRequest,
+) -> Result<serde_json::Value> {
+    let url = base_url.trim_end_matches('/');
+
+    let rpc_request = JsonRpcRequest::new(1, req.method, Some(req.params));
+    let request_id = rpc_request.id;
+
+    let res = client
+        .post(url)
+        .json(&rpc_request)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .send()
+        .await
+        .context("Failed to send request to MCP server")?;
+
+    let status = res.status();
+    let is_event_stream = res
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/event-stream"));
+
+    if !is_event_stream {
+        let body_text = res.text().await.context("Failed to read response body")?;
+
+        if body_text.trim().is_empty() {
+            return Err(anyhow::anyhow!("Empty response body from MCP server"));
+        }
+
+        let json_response: serde_json::Value = serde_json::from_str(&body_text)
+            .with_context(|| format!("Failed to parse JSON response. Status: {}, Body: {}", status, body_text))?;
+
+        if !status.is_success() {
+            eprintln!("[!] MCP server returned error: {}", status);
+            eprintln!("[!] Response body: {}", body_text);
+        }
+
+        return Ok(json_response);
+    }
+
+    // Consume the full SSE stream instead of stopping at the first `data:`
+    // line: forward every message that isn't ours as a notification, and
+    // resolve once our own request id shows up.
+    //
+    // Buffered as raw bytes, not `String`: a multibyte UTF-8 character can
+    // be split across two chunks, and decoding each chunk independently
+    // (e.g. via `from_utf8_lossy`) would corrupt it into replacement
+    // characters. The `\n\n` event delimiter is pure ASCII, so splitting on
+    // it at the byte level is always safe; only a complete, reassembled
+    // event is ever decoded to UTF-8.
+    let mut stream = res.bytes_stream();
+    let mut buffer: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.context("Failed to read SSE chunk from MCP server")?;
+        buffer.extend_from_slice(&chunk);
+
+        while let Some(event_end) = find_subslice(&buffer, b"\n\n") {
+            let event_bytes: Vec<u8> = buffer.drain(..event_end + 2).collect();
+            if let Some(response) = process_sse_event(&event_bytes, request_id)? {
+                return Ok(response);
+            }
+        }
+    }
+
+    // The stream can end without a trailing blank line after the last event
+    // (the baseline's whole-body `.lines()` parse tolerated this); flush
+    // whatever didn't end in a `\n\n` instead of dropping it on the floor.
+    if let Some(response) = process_sse_event(&buffer, request_id)? {
+        return Ok(response);
+    }
+
+    Err(anyhow::anyhow!(
+        "SSE stream ended before a response for request id {:?} arrived",
+        request_id
+    ))
+}
+
+/// Parse one SSE event's `data:` lines, forwarding anything that isn't our
+/// response as a notification. Returns `Some` once a `data:` line carries
+/// our request id.
+fn process_sse_event(event_bytes: &[u8], request_id: Option<i64>) -> Result<Option<serde_json::Value>> {
+    let event = String::from_utf8_lossy(event_bytes);
+
+    for line in event.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+
+        let message: serde_json::Value = match serde_json::from_str(data) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+
+        if message.get("id").and_then(|id| id.as_i64()) == request_id {
+            return Ok(Some(message));
+        }
+
+        // Server-initiated notification (e.g. notifications/progress) - forward immediately.
+        println!("{}", serde_json::to_string(&message)?);
+    }
+
+    Ok(None)
+}
+
+/// Byte-level `str::find`, so we can locate the `\n\n` event delimiter
+/// before any UTF-8 decoding happens.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_subslice_locates_delimiter() {
+        let haystack = b"data: {}\n\ndata: more\n\n";
+        assert_eq!(find_subslice(haystack, b"\n\n"), Some(8));
+        assert_eq!(find_subslice(b"no delimiter here", b"\n\n"), None);
+    }
+
+    #[test]
+    fn find_subslice_does_not_split_a_multibyte_char() {
+        // "é" is the two-byte UTF-8 sequence 0xC3 0xA9; a needle search must
+        // never report a match inside it.
+        let haystack = "caf\u{e9}\n\n".as_bytes();
+        assert_eq!(find_subslice(haystack, b"\n\n"), Some(haystack.len() - 2));
+    }
+
+    #[test]
+    fn process_sse_event_returns_matching_response() {
+        let event = b"data: {\"jsonrpc\":\"2.0\",\"id\":7,\"result\":{}}\n\n";
+        let result = process_sse_event(event, Some(7)).unwrap();
+        assert_eq!(result.unwrap()["id"], 7);
+    }
+
+    #[test]
+    fn process_sse_event_forwards_non_matching_message_and_returns_none() {
+        let event = b"data: {\"jsonrpc\":\"2.0\",\"method\":\"notifications/progress\"}\n\n";
+        let result = process_sse_event(event, Some(7)).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn reassembles_an_event_split_across_chunk_boundaries() {
+        // A multibyte character ("é", 0xC3 0xA9) split across two chunks
+        // must decode correctly once both chunks are buffered together,
+        // not be corrupted into a replacement character per-chunk.
+        let whole = "data: {\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"caf\u{e9}\"}\n\n".as_bytes();
+        let split_at = whole.len() - 3; // splits inside the 2-byte 'é'
+        let mut buffer: Vec<u8> = whole[..split_at].to_vec();
+        buffer.extend_from_slice(&whole[split_at..]);
+
+        let event_end = find_subslice(&buffer, b"\n\n").unwrap();
+        let event_bytes: Vec<u8> = buffer.drain(..event_end + 2).collect();
+        let result = process_sse_event(&event_bytes, Some(1)).unwrap().unwrap();
+        assert_eq!(result["result"], "café");
+    }
+}
@@ -0,0 +1,44 @@
+// Merges a fixed set of extra fields into every outbound request's params,
+// e.g. an API key or client identifier a backend expects on every call but
+// that the calling client has no reason to know about. The interceptor
+// chain operates on JSON-RPC messages rather than raw HTTP, so "header"
+// injection for HTTP backends and "param" injection for everything else
+// are unified into one params-level interceptor.
+
+use async_trait::async_trait;
+
+use super::Interceptor;
+use crate::protocol::JsonRpcRequest;
+
+pub struct ParamInjectInterceptor {
+    extra_params: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ParamInjectInterceptor {
+    pub fn new(extra_params: serde_json::Map<String, serde_json::Value>) -> Self {
+        ParamInjectInterceptor { extra_params }
+    }
+}
+
+#[async_trait]
+impl Interceptor for ParamInjectInterceptor {
+    async fn on_request(&self, request: &mut JsonRpcRequest) {
+        if self.extra_params.is_empty() {
+            return;
+        }
+
+        let params = request
+            .params
+            .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+
+        let serde_json::Value::Object(map) = params else {
+            return;
+        };
+
+        for (key, value) in &self.extra_params {
+            map.insert(key.clone(), value.clone());
+        }
+    }
+
+    async fn on_response(&self, _response: &mut serde_json::Value) {}
+}
@@ -0,0 +1,114 @@
+// Ordered request/response interceptor pipeline shared by every transport.
+// Each JSON-RPC request read from stdin runs through `on_request` before
+// being dispatched to a backend, and each backend response runs through
+// `on_response` before being written back to stdout. This generalizes what
+// used to be a one-off Unicode-unescaping step hardcoded into the HTTP
+// transport into something pluggable and shared across HTTP, STDIO, named
+// pipe, and WebSocket backends alike.
+//
+// Streamed notifications (SSE progress events, WebSocket/STDIO
+// server-initiated messages) are forwarded straight to stdout by their
+// respective transports and do not pass through this chain.
+
+pub mod inject;
+pub mod redact;
+pub mod unicode;
+
+pub use inject::ParamInjectInterceptor;
+pub use redact::RedactInterceptor;
+pub use unicode::UnicodeDecodeInterceptor;
+
+use async_trait::async_trait;
+
+use crate::protocol::JsonRpcRequest;
+
+#[async_trait]
+pub trait Interceptor: Send + Sync {
+    /// Called with the incoming client request before it is dispatched to a
+    /// backend. Default is a no-op so interceptors only need to implement
+    /// the side they care about.
+    async fn on_request(&self, _request: &mut JsonRpcRequest) {}
+
+    /// Called with a backend's raw JSON-RPC response before its `result` is
+    /// extracted and written back to the client.
+    async fn on_response(&self, _response: &mut serde_json::Value) {}
+}
+
+/// Runs a fixed, ordered list of interceptors over every request and
+/// response. Interceptors run front-to-back in both directions: the first
+/// interceptor in the list sees the request first, and also sees the
+/// response first (not an onion-style pipeline, where the first interceptor
+/// would see the response last). This lets a later interceptor, e.g.
+/// `RedactInterceptor`'s length truncation, run on text `UnicodeDecodeInterceptor`
+/// has already unescaped, rather than on raw `\uXXXX` escapes.
+#[derive(Default)]
+pub struct InterceptorChain {
+    interceptors: Vec<Box<dyn Interceptor>>,
+}
+
+impl InterceptorChain {
+    pub fn new(interceptors: Vec<Box<dyn Interceptor>>) -> Self {
+        InterceptorChain { interceptors }
+    }
+
+    pub async fn on_request(&self, request: &mut JsonRpcRequest) {
+        for interceptor in &self.interceptors {
+            interceptor.on_request(request).await;
+        }
+    }
+
+    pub async fn on_response(&self, response: &mut serde_json::Value) {
+        for interceptor in &self.interceptors {
+            interceptor.on_response(response).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Appends its `name` to a shared log from both `on_request` and
+    /// `on_response`, so a chain of these can prove call order.
+    struct RecordingInterceptor {
+        name: &'static str,
+        log: std::sync::Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl Interceptor for RecordingInterceptor {
+        async fn on_request(&self, _request: &mut JsonRpcRequest) {
+            self.log.lock().unwrap().push(self.name);
+        }
+
+        async fn on_response(&self, _response: &mut serde_json::Value) {
+            self.log.lock().unwrap().push(self.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn request_and_response_both_run_front_to_back() {
+        let log = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let chain = InterceptorChain::new(vec![
+            Box::new(RecordingInterceptor {
+                name: "first",
+                log: log.clone(),
+            }),
+            Box::new(RecordingInterceptor {
+                name: "second",
+                log: log.clone(),
+            }),
+        ]);
+
+        let mut request = JsonRpcRequest::new(1, "tools/call", None);
+        chain.on_request(&mut request).await;
+        assert_eq!(*log.lock().unwrap(), vec!["first", "second"]);
+
+        log.lock().unwrap().clear();
+        let mut response = serde_json::json!({});
+        chain.on_response(&mut response).await;
+        assert_eq!(*log.lock().unwrap(), vec!["first", "second"]);
+    }
+}
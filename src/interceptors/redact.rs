@@ -0,0 +1,66 @@
+// Keeps oversized or sensitive fields out of responses before they reach
+// the client, e.g. to avoid dumping huge payloads or secrets a backend
+// includes in its response into a log that captures proxy stdout.
+
+use async_trait::async_trait;
+
+use super::Interceptor;
+use crate::protocol::JsonRpcRequest;
+
+pub struct RedactInterceptor {
+    /// Object field names to replace with a fixed placeholder, wherever
+    /// they appear in the response.
+    redact_fields: Vec<String>,
+    /// String values longer than this are truncated. `usize::MAX` disables
+    /// truncation.
+    max_text_len: usize,
+}
+
+impl RedactInterceptor {
+    pub fn new(redact_fields: Vec<String>, max_text_len: usize) -> Self {
+        RedactInterceptor {
+            redact_fields,
+            max_text_len,
+        }
+    }
+
+    fn redact_value(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for field in &self.redact_fields {
+                    if let Some(entry) = map.get_mut(field) {
+                        *entry = serde_json::Value::String("[redacted]".to_string());
+                    }
+                }
+                for entry in map.values_mut() {
+                    self.redact_value(entry);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items.iter_mut() {
+                    self.redact_value(item);
+                }
+            }
+            serde_json::Value::String(text) => {
+                if text.len() > self.max_text_len {
+                    let mut end = self.max_text_len;
+                    while end > 0 && !text.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    text.truncate(end);
+                    text.push_str("... [truncated]");
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Interceptor for RedactInterceptor {
+    async fn on_request(&self, _request: &mut JsonRpcRequest) {}
+
+    async fn on_response(&self, response: &mut serde_json::Value) {
+        self.redact_value(response);
+    }
+}
@@ -0,0 +1,43 @@
+// The original, always-on interceptor: some MCP servers emit literal
+// `\uXXXX` escapes inside `result.content[].text` instead of letting their
+// JSON encoder do it, so decode the common ones back to real characters.
+
+use async_trait::async_trait;
+
+use super::Interceptor;
+use crate::protocol::JsonRpcRequest;
+
+pub struct UnicodeDecodeInterceptor;
+
+#[async_trait]
+impl Interceptor for UnicodeDecodeInterceptor {
+    async fn on_request(&self, _request: &mut JsonRpcRequest) {}
+
+    async fn on_response(&self, response: &mut serde_json::Value) {
+        let Some(content_array) = response
+            .get_mut("result")
+            .and_then(|result| result.get_mut("content"))
+            .and_then(|content| content.as_array_mut())
+        else {
+            return;
+        };
+
+        for item in content_array.iter_mut() {
+            let Some(text) = item.get_mut("text") else {
+                continue;
+            };
+            let Some(text_str) = text.as_str() else {
+                continue;
+            };
+
+            let decoded = text_str
+                .replace("\\u0027", "'")
+                .replace("\\u0060", "`")
+                .replace("\\u0022", "\"")
+                .replace("\\u003C", "<")
+                .replace("\\u003E", ">")
+                .replace("\\n", "\n");
+            *text = serde_json::Value::String(decoded);
+        }
+    }
+}
@@ -5,13 +5,23 @@
 use anyhow::{Context, Result};
 use argh::FromArgs;
 use reqwest::Client;
-use serde::{Deserialize, Serialize};
 use std::io::{self, BufRead, BufReader};
-use std::process::Stdio;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader as TokioBufReader};
-use tokio::process::{Child, Command as TokioCommand};
-use tokio::fs::OpenOptions;
-use tokio::net::UnixStream;
+use tokio::sync::mpsc;
+
+mod aggregator;
+mod clients;
+mod config;
+mod http;
+mod interceptors;
+mod protocol;
+mod transport;
+
+use aggregator::Aggregator;
+use clients::{NamedPipeMcpClient, ReadyGate, StdioMcpClient, TlsOptions, WebSocketMcpClient};
+use config::ProxyConfig;
+use http::proxy_mcp_request_http;
+use interceptors::{InterceptorChain, ParamInjectInterceptor, RedactInterceptor, UnicodeDecodeInterceptor};
+use protocol::{JsonRpcRequest, JsonRpcResponse, MCPRequest};
 
 // ----------------------------
 // Structs for request/response
@@ -36,10 +46,42 @@ struct Args {
     #[argh(option, short = 'p')]
     pipe: Option<String>,
     
-    /// timeout in seconds for HTTP requests (ignored for STDIO and named pipe)
+    /// timeout in seconds for HTTP requests (ignored for STDIO, named pipe and WebSocket)
     #[argh(option, short = 't', default = "30")]
     timeout: u64,
-    
+
+    /// skip TLS certificate verification for wss:// backends (dev/self-signed servers only)
+    #[argh(switch)]
+    tls_insecure: bool,
+
+    /// path to a PEM-encoded CA bundle to trust for wss:// backends
+    #[argh(option)]
+    tls_ca: Option<String>,
+
+    /// path to a JSON config file listing multiple backends to aggregate behind one proxy
+    #[argh(option)]
+    config: Option<String>,
+
+    /// substring to wait for on the STDIO backend's stderr before sending its first request
+    #[argh(option)]
+    ready_pattern: Option<String>,
+
+    /// seconds to wait for --ready-pattern before giving up and sending the request anyway
+    #[argh(option, default = "10")]
+    ready_timeout: u64,
+
+    /// object field name to redact (replace with a placeholder) in every response; may be repeated
+    #[argh(option)]
+    redact_field: Vec<String>,
+
+    /// truncate response string values longer than this many bytes
+    #[argh(option)]
+    redact_max_len: Option<usize>,
+
+    /// extra request param to inject as `key=value` into every outbound request; may be repeated
+    #[argh(option)]
+    inject_param: Vec<String>,
+
     /// enable verbose logging
     #[argh(switch, short = 'v')]
     verbose: bool,
@@ -50,299 +92,135 @@ enum TransportMode {
     Http,
     Stdio,
     NamedPipe,
-}
-
-#[derive(Serialize, Deserialize)]
-struct MCPRequest {
-    method: String,
-    params: serde_json::Value,
-}
-
-// MCP JSON-RPC structures
-#[derive(Serialize, Deserialize)]
-struct JsonRpcRequest {
-    jsonrpc: String,
-    id: Option<i32>,
-    method: String,
-    params: Option<serde_json::Value>,
-}
-
-#[derive(Serialize)]
-struct JsonRpcResponse {
-    jsonrpc: String,
-    id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    result: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<serde_json::Value>,
+    WebSocket,
 }
 
 // ----------------------------
 // MCP Client Logic
 // ----------------------------
 
-struct StdioMcpClient {
-    process: Child,
-    stdin: tokio::process::ChildStdin,
-    stdout: TokioBufReader<tokio::process::ChildStdout>,
+async fn proxy_mcp_request_stdio(stdio_client: &mut StdioMcpClient, req: MCPRequest) -> Result<serde_json::Value> {
+    stdio_client.call(&req.method, Some(req.params)).await
 }
 
-impl StdioMcpClient {
-    async fn new(command: &str, args: &[String]) -> Result<Self> {
-        let mut cmd = TokioCommand::new(command);
-        cmd.args(args)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
-        
-        let mut process = cmd.spawn().context("Failed to spawn MCP server process")?;
-        
-        let stdin = process.stdin.take().context("Failed to get stdin")?;
-        let stdout = process.stdout.take().context("Failed to get stdout")?;
-        let stdout = TokioBufReader::new(stdout);
-        
-        Ok(StdioMcpClient {
-            process,
-            stdin,
-            stdout,
-        })
-    }
-    
-    async fn send_request(&mut self, request: &str) -> Result<String> {
-        // Send request
-        self.stdin.write_all(request.as_bytes()).await?;
-        self.stdin.write_all(b"\n").await?;
-        self.stdin.flush().await?;
-        
-        // Read response
-        let mut response = String::new();
-        self.stdout.read_line(&mut response).await?;
-        
-        Ok(response.trim().to_string())
-    }
+async fn proxy_mcp_request_named_pipe(pipe_client: &NamedPipeMcpClient, req: MCPRequest) -> Result<serde_json::Value> {
+    pipe_client.call(&req.method, Some(req.params)).await
 }
 
-struct NamedPipeMcpClient {
-    pipe_path: String,
+async fn proxy_mcp_request_websocket(ws_client: &WebSocketMcpClient, req: MCPRequest) -> Result<serde_json::Value> {
+    ws_client.call(&req.method, Some(req.params)).await
 }
 
-impl NamedPipeMcpClient {
-    fn new(pipe_path: &str) -> Self {
-        NamedPipeMcpClient {
-            pipe_path: pipe_path.to_string(),
-        }
-    }
-    
-    async fn send_request(&self, request: &str) -> Result<String> {
-        // For named pipes, we open the pipe, write the request, and read the response
-        // This assumes the named pipe server can handle request/response pairs
-        
-        // Try opening as a Unix socket first (more common for MCP servers)
-        if let Ok(mut stream) = UnixStream::connect(&self.pipe_path).await {
-            // Send request
-            stream.write_all(request.as_bytes()).await?;
-            stream.write_all(b"\n").await?;
-            
-            // Read response
-            let mut reader = TokioBufReader::new(stream);
-            let mut response = String::new();
-            reader.read_line(&mut response).await?;
-            
-            return Ok(response.trim().to_string());
+/// Forward server-initiated messages (notifications, or requests with no
+/// matching pending id) straight to stdout as they arrive, the same way the
+/// HTTP transport forwards SSE notifications inline.
+pub(crate) fn spawn_notification_forwarder(mut notifications: mpsc::UnboundedReceiver<serde_json::Value>) {
+    tokio::spawn(async move {
+        while let Some(notification) = notifications.recv().await {
+            if let Ok(line) = serde_json::to_string(&notification) {
+                println!("{}", line);
+            }
         }
-        
-        // Fallback to named pipe (FIFO) approach
-        // Open the pipe for writing (send request)
-        let mut write_file = OpenOptions::new()
-            .write(true)
-            .open(&self.pipe_path)
-            .await
-            .with_context(|| format!("Failed to open named pipe for writing: {}", self.pipe_path))?;
-            
-        write_file.write_all(request.as_bytes()).await?;
-        write_file.write_all(b"\n").await?;
-        write_file.flush().await?;
-        
-        // For FIFO pipes, we typically need a separate read pipe or the same pipe
-        // This is a simplified implementation - you might need to adjust based on your server
-        let read_file = OpenOptions::new()
-            .read(true)
-            .open(&self.pipe_path)
-            .await
-            .with_context(|| format!("Failed to open named pipe for reading: {}", self.pipe_path))?;
-            
-        let mut reader = TokioBufReader::new(read_file);
-        let mut response = String::new();
-        reader.read_line(&mut response).await?;
-        
-        Ok(response.trim().to_string())
-    }
+    });
 }
 
-async fn proxy_mcp_request_http(client: &Client, base_url: &str, req: MCPRequest) -> Result<serde_json::Value> {
-    let url = base_url.trim_end_matches('/');
-    
-    // Create JSON-RPC request
-    let rpc_request = JsonRpcRequest {
-        jsonrpc: "2.0".to_string(),
-        id: Some(1),
-        method: req.method.clone(),
-        params: Some(req.params.clone()),
-    };
-
-    let res = client
-        .post(url)
-        .json(&rpc_request)
-        .header("Content-Type", "application/json")
-        .header("Accept", "application/json, text/event-stream")
-        .send()
-        .await
-        .context("Failed to send request to MCP server")?;
-
-    let status = res.status();
-    let body_text = res.text().await.context("Failed to read response body")?;
+// ----------------------------
+// Main loop (stdin/stdout)
+// ----------------------------
 
-    if body_text.trim().is_empty() {
-        return Err(anyhow::anyhow!("Empty response body from MCP server"));
-    }
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Args = argh::from_env();
 
-    // Handle Server-Sent Events (SSE) format
-    let mut json_response: serde_json::Value = if body_text.starts_with("event:") || body_text.contains("data:") {
-        // Parse SSE format
-        let mut json_data = String::new();
-        for line in body_text.lines() {
-            if line.starts_with("data: ") {
-                json_data = line.strip_prefix("data: ").unwrap_or("").to_string();
-                break;
-            }
-        }
-        
-        if json_data.is_empty() {
-            return Err(anyhow::anyhow!("No data found in SSE response"));
-        }
-        
-        serde_json::from_str(&json_data)
-            .with_context(|| format!("Failed to parse SSE JSON data. Status: {}, Data: {}", status, json_data))?
-    } else {
-        // Handle regular JSON response
-        serde_json::from_str(&body_text)
-            .with_context(|| format!("Failed to parse JSON response. Status: {}, Body: {}", status, body_text))?
+    let tls = TlsOptions {
+        insecure: args.tls_insecure,
+        ca_path: args.tls_ca.clone(),
     };
 
-    // Decode Unicode escapes in the response content
-    if let Some(result) = json_response.get_mut("result") {
-        if let Some(content) = result.get_mut("content") {
-            if let Some(content_array) = content.as_array_mut() {
-                for item in content_array.iter_mut() {
-                    if let Some(text) = item.get_mut("text") {
-                        if let Some(text_str) = text.as_str() {
-                            // Decode common Unicode escapes
-                            let decoded = text_str
-                                .replace("\\u0027", "'")
-                                .replace("\\u0060", "`")
-                                .replace("\\u0022", "\"")
-                                .replace("\\u003C", "<")
-                                .replace("\\u003E", ">")
-                                .replace("\\n", "\n");
-                            *text = serde_json::Value::String(decoded);
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let client = Client::builder()
+        .timeout(std::time::Duration::from_secs(args.timeout))
+        .build()
+        .context("Failed to create HTTP client")?;
 
-    if !status.is_success() {
-        eprintln!("[!] MCP server returned error: {}", status);
-        eprintln!("[!] Response body: {}", body_text);
+    // Every request and response passes through this chain: the Unicode
+    // decode is always on to preserve past behavior, while redaction and
+    // param injection are inert unless configured.
+    let mut extra_params = serde_json::Map::new();
+    for pair in &args.inject_param {
+        let (key, value) = pair
+            .split_once('=')
+            .with_context(|| format!("--inject-param must be key=value, got '{}'", pair))?;
+        extra_params.insert(key.to_string(), serde_json::Value::String(value.to_string()));
     }
+    let interceptors = InterceptorChain::new(vec![
+        Box::new(UnicodeDecodeInterceptor),
+        Box::new(RedactInterceptor::new(
+            args.redact_field.clone(),
+            args.redact_max_len.unwrap_or(usize::MAX),
+        )),
+        Box::new(ParamInjectInterceptor::new(extra_params)),
+    ]);
 
-    Ok(json_response)
-}
-
-async fn proxy_mcp_request_stdio(stdio_client: &mut StdioMcpClient, req: MCPRequest) -> Result<serde_json::Value> {
-    // Create JSON-RPC request
-    let rpc_request = JsonRpcRequest {
-        jsonrpc: "2.0".to_string(),
-        id: Some(1),
-        method: req.method.clone(),
-        params: Some(req.params.clone()),
-    };
-    
-    let request_json = serde_json::to_string(&rpc_request)?;
-    let response_json = stdio_client.send_request(&request_json).await?;
-    
-    let json_response: serde_json::Value = serde_json::from_str(&response_json)
-        .with_context(|| format!("Failed to parse JSON response: {}", response_json))?;
-    
-    Ok(json_response)
-}
+    let ready_gate = args.ready_pattern.as_ref().map(|pattern| ReadyGate {
+        pattern: pattern.clone(),
+        timeout: std::time::Duration::from_secs(args.ready_timeout),
+    });
 
-async fn proxy_mcp_request_named_pipe(pipe_client: &NamedPipeMcpClient, req: MCPRequest) -> Result<serde_json::Value> {
-    // Create JSON-RPC request
-    let rpc_request = JsonRpcRequest {
-        jsonrpc: "2.0".to_string(),
-        id: Some(1),
-        method: req.method.clone(),
-        params: Some(req.params.clone()),
+    // A --config aggregates several backends behind one proxy; otherwise
+    // fall back to the single backend selected by -u/-c/-p.
+    let mut aggregator = if let Some(config_path) = &args.config {
+        if args.verbose {
+            eprintln!("[INFO] Starting MCP proxy tool (aggregating backends from {})", config_path);
+        }
+        let proxy_config = ProxyConfig::load(config_path)?;
+        Some(Aggregator::connect(&proxy_config.backends, &client, &tls, ready_gate.as_ref()).await?)
+    } else {
+        None
     };
-    
-    let request_json = serde_json::to_string(&rpc_request)?;
-    let response_json = pipe_client.send_request(&request_json).await?;
-    
-    let json_response: serde_json::Value = serde_json::from_str(&response_json)
-        .with_context(|| format!("Failed to parse JSON response: {}", response_json))?;
-    
-    Ok(json_response)
-}
-
-// ----------------------------
-// Main loop (stdin/stdout)
-// ----------------------------
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let args: Args = argh::from_env();
-
-    // Determine transport mode
-    let transport_mode = if args.url.is_some() {
-        TransportMode::Http
+    // Determine transport mode for the single-backend case
+    let transport_mode = if aggregator.is_some() {
+        None
+    } else if matches!(args.url.as_deref(), Some(url) if url.starts_with("ws://") || url.starts_with("wss://")) {
+        Some(TransportMode::WebSocket)
+    } else if args.url.is_some() {
+        Some(TransportMode::Http)
     } else if args.command.is_some() {
-        TransportMode::Stdio
+        Some(TransportMode::Stdio)
     } else if args.pipe.is_some() {
-        TransportMode::NamedPipe
+        Some(TransportMode::NamedPipe)
     } else {
-        eprintln!("Error: Must specify either -u/--url for HTTP, -c/--command for STDIO, or -p/--pipe for named pipe transport");
+        eprintln!("Error: Must specify either --config for multiple backends, -u/--url for HTTP/WebSocket, -c/--command for STDIO, or -p/--pipe for named pipe transport");
         std::process::exit(1);
     };
 
     if args.verbose {
-        eprintln!("[INFO] Starting MCP proxy tool");
-        eprintln!("[INFO] Transport mode: {:?}", transport_mode);
-        match &transport_mode {
-            TransportMode::Http => {
-                eprintln!("[INFO] Target MCP server: {}", args.url.as_ref().unwrap());
-            }
-            TransportMode::Stdio => {
-                let cmd_args = args.args.as_deref().unwrap_or("");
-                eprintln!("[INFO] Target MCP command: {} {}", 
-                    args.command.as_ref().unwrap(),
-                    cmd_args);
-            }
-            TransportMode::NamedPipe => {
-                eprintln!("[INFO] Target MCP named pipe: {}", args.pipe.as_ref().unwrap());
+        if let Some(transport_mode) = &transport_mode {
+            eprintln!("[INFO] Starting MCP proxy tool");
+            eprintln!("[INFO] Transport mode: {:?}", transport_mode);
+            match transport_mode {
+                TransportMode::Http => {
+                    eprintln!("[INFO] Target MCP server: {}", args.url.as_ref().unwrap());
+                }
+                TransportMode::Stdio => {
+                    let cmd_args = args.args.as_deref().unwrap_or("");
+                    eprintln!("[INFO] Target MCP command: {} {}",
+                        args.command.as_ref().unwrap(),
+                        cmd_args);
+                }
+                TransportMode::NamedPipe => {
+                    eprintln!("[INFO] Target MCP named pipe: {}", args.pipe.as_ref().unwrap());
+                }
+                TransportMode::WebSocket => {
+                    eprintln!("[INFO] Target MCP WebSocket: {}", args.url.as_ref().unwrap());
+                }
             }
+            eprintln!("[INFO] Timeout: {} seconds", args.timeout);
         }
-        eprintln!("[INFO] Timeout: {} seconds", args.timeout);
     }
-    
-    let client = Client::builder()
-        .timeout(std::time::Duration::from_secs(args.timeout))
-        .build()
-        .context("Failed to create HTTP client")?;
 
     // Initialize STDIO client if needed
-    let mut stdio_client = if let TransportMode::Stdio = transport_mode {
+    let mut stdio_client = if let Some(TransportMode::Stdio) = transport_mode {
         let command = args.command.as_ref().unwrap();
         let cmd_args_str = args.args.as_deref().unwrap_or("");
         let cmd_args: Vec<String> = if cmd_args_str.is_empty() {
@@ -350,19 +228,33 @@ async fn main() -> Result<()> {
         } else {
             cmd_args_str.split_whitespace().map(|s| s.to_string()).collect()
         };
-        Some(StdioMcpClient::new(command, &cmd_args).await?)
+        let mut client = StdioMcpClient::new(command, &cmd_args, ready_gate.clone()).await?;
+        spawn_notification_forwarder(client.take_notifications());
+        Some(client)
     } else {
         None
     };
 
     // Initialize named pipe client if needed
-    let pipe_client = if let TransportMode::NamedPipe = transport_mode {
+    let pipe_client = if let Some(TransportMode::NamedPipe) = transport_mode {
         let pipe_path = args.pipe.as_ref().unwrap();
-        Some(NamedPipeMcpClient::new(pipe_path))
+        let mut client = NamedPipeMcpClient::connect(pipe_path).await?;
+        spawn_notification_forwarder(client.take_notifications());
+        Some(client)
     } else {
         None
     };
-    
+
+    // Initialize WebSocket client if needed
+    let ws_client = if let Some(TransportMode::WebSocket) = transport_mode {
+        let url = args.url.as_ref().unwrap();
+        let mut client = WebSocketMcpClient::connect(url, &tls).await?;
+        spawn_notification_forwarder(client.take_notifications());
+        Some(client)
+    } else {
+        None
+    };
+
     let stdin = io::stdin();
     let reader = BufReader::new(stdin);
     
@@ -379,14 +271,15 @@ async fn main() -> Result<()> {
         }
         
         // Parse the JSON-RPC request
-        let request: JsonRpcRequest = match serde_json::from_str(&line) {
+        let mut request: JsonRpcRequest = match serde_json::from_str(&line) {
             Ok(req) => req,
             Err(e) => {
                 eprintln!("[!] Failed to parse JSON-RPC request: {}", e);
                 continue;
             }
         };
-        
+        interceptors.on_request(&mut request).await;
+
         // Handle different MCP methods
         match request.method.as_str() {
             "initialize" => {
@@ -423,38 +316,61 @@ async fn main() -> Result<()> {
             }
             "tools/list" => {
                 if args.verbose {
-                    match &transport_mode {
+                    if let Some(agg) = &aggregator {
+                        eprintln!("[INFO] Proxying tools/list request to {} backend(s)", agg.backend_count());
+                    } else {
+                        match transport_mode.as_ref().unwrap() {
+                            TransportMode::Http => {
+                                eprintln!("[INFO] Proxying tools/list request to {}", args.url.as_ref().unwrap());
+                            }
+                            TransportMode::Stdio => {
+                                eprintln!("[INFO] Proxying tools/list request to STDIO command");
+                            }
+                            TransportMode::NamedPipe => {
+                                eprintln!("[INFO] Proxying tools/list request to named pipe: {}", args.pipe.as_ref().unwrap());
+                            }
+                            TransportMode::WebSocket => {
+                                eprintln!("[INFO] Proxying tools/list request to WebSocket: {}", args.url.as_ref().unwrap());
+                            }
+                        }
+                    }
+                }
+
+                let proxy_result: Result<serde_json::Value> = if let Some(agg) = &mut aggregator {
+                    Ok(agg.list_tools().await)
+                } else {
+                    // Get the tool list from the remote server. `request.params`
+                    // already ran through the interceptor chain (e.g.
+                    // `ParamInjectInterceptor`), so forward it instead of an
+                    // empty map for consistency with the `tools/call` path.
+                    let mcp_req = MCPRequest {
+                        method: "tools/list".to_string(),
+                        params: request
+                            .params
+                            .clone()
+                            .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new())),
+                    };
+
+                    match transport_mode.as_ref().unwrap() {
                         TransportMode::Http => {
-                            eprintln!("[INFO] Proxying tools/list request to {}", args.url.as_ref().unwrap());
+                            proxy_mcp_request_http(&client, args.url.as_ref().unwrap(), mcp_req).await
                         }
                         TransportMode::Stdio => {
-                            eprintln!("[INFO] Proxying tools/list request to STDIO command");
+                            proxy_mcp_request_stdio(stdio_client.as_mut().unwrap(), mcp_req).await
                         }
                         TransportMode::NamedPipe => {
-                            eprintln!("[INFO] Proxying tools/list request to named pipe: {}", args.pipe.as_ref().unwrap());
+                            proxy_mcp_request_named_pipe(pipe_client.as_ref().unwrap(), mcp_req).await
+                        }
+                        TransportMode::WebSocket => {
+                            proxy_mcp_request_websocket(ws_client.as_ref().unwrap(), mcp_req).await
                         }
-                    }
-                }
-                // Get the tool list from the remote server
-                let mcp_req = MCPRequest {
-                    method: "tools/list".to_string(),
-                    params: serde_json::Value::Object(serde_json::Map::new()),
-                };
-                
-                let proxy_result = match &transport_mode {
-                    TransportMode::Http => {
-                        proxy_mcp_request_http(&client, args.url.as_ref().unwrap(), mcp_req).await
-                    }
-                    TransportMode::Stdio => {
-                        proxy_mcp_request_stdio(stdio_client.as_mut().unwrap(), mcp_req).await
-                    }
-                    TransportMode::NamedPipe => {
-                        proxy_mcp_request_named_pipe(pipe_client.as_ref().unwrap(), mcp_req).await
                     }
                 };
-                
+
                 match proxy_result {
-                    Ok(result) => {
+                    Ok(mut result) => {
+                        interceptors.on_response(&mut result).await;
+
                         // Extract the inner result from the server response
                         let tools_result = if let Some(inner_result) = result.get("result") {
                             inner_result.clone()
@@ -488,39 +404,58 @@ async fn main() -> Result<()> {
                 }
             }
             "tools/call" => {
+                let call_params = request.params.clone().unwrap_or_default();
+
                 if args.verbose {
-                    match &transport_mode {
+                    if let Some(agg) = &aggregator {
+                        eprintln!("[INFO] Proxying tools/call request to {} backend(s)", agg.backend_count());
+                    } else {
+                        match transport_mode.as_ref().unwrap() {
+                            TransportMode::Http => {
+                                eprintln!("[INFO] Proxying tools/call request to {}", args.url.as_ref().unwrap());
+                            }
+                            TransportMode::Stdio => {
+                                eprintln!("[INFO] Proxying tools/call request to STDIO command");
+                            }
+                            TransportMode::NamedPipe => {
+                                eprintln!("[INFO] Proxying tools/call request to named pipe: {}", args.pipe.as_ref().unwrap());
+                            }
+                            TransportMode::WebSocket => {
+                                eprintln!("[INFO] Proxying tools/call request to WebSocket: {}", args.url.as_ref().unwrap());
+                            }
+                        }
+                    }
+                }
+
+                let proxy_result: Result<serde_json::Value> = if let Some(agg) = &mut aggregator {
+                    agg.call_tool(call_params).await
+                } else {
+                    // Proxy the tool call to the remote server
+                    let mcp_req = MCPRequest {
+                        method: "tools/call".to_string(),
+                        params: call_params,
+                    };
+
+                    match transport_mode.as_ref().unwrap() {
                         TransportMode::Http => {
-                            eprintln!("[INFO] Proxying tools/call request to {}", args.url.as_ref().unwrap());
+                            proxy_mcp_request_http(&client, args.url.as_ref().unwrap(), mcp_req).await
                         }
                         TransportMode::Stdio => {
-                            eprintln!("[INFO] Proxying tools/call request to STDIO command");
+                            proxy_mcp_request_stdio(stdio_client.as_mut().unwrap(), mcp_req).await
                         }
                         TransportMode::NamedPipe => {
-                            eprintln!("[INFO] Proxying tools/call request to named pipe: {}", args.pipe.as_ref().unwrap());
+                            proxy_mcp_request_named_pipe(pipe_client.as_ref().unwrap(), mcp_req).await
+                        }
+                        TransportMode::WebSocket => {
+                            proxy_mcp_request_websocket(ws_client.as_ref().unwrap(), mcp_req).await
                         }
-                    }
-                }
-                // Proxy the tool call to the remote server
-                let mcp_req = MCPRequest {
-                    method: "tools/call".to_string(),
-                    params: request.params.unwrap_or_default(),
-                };
-                
-                let proxy_result = match &transport_mode {
-                    TransportMode::Http => {
-                        proxy_mcp_request_http(&client, args.url.as_ref().unwrap(), mcp_req).await
-                    }
-                    TransportMode::Stdio => {
-                        proxy_mcp_request_stdio(stdio_client.as_mut().unwrap(), mcp_req).await
-                    }
-                    TransportMode::NamedPipe => {
-                        proxy_mcp_request_named_pipe(pipe_client.as_ref().unwrap(), mcp_req).await
                     }
                 };
-                
+
                 match proxy_result {
-                    Ok(result) => {
+                    Ok(mut result) => {
+                        interceptors.on_response(&mut result).await;
+
                         // Extract the inner result from the server response
                         let call_result = if let Some(inner_result) = result.get("result") {
                             inner_result.clone()
@@ -0,0 +1,42 @@
+// Shared JSON-RPC / MCP message types used by every transport.
+
+use serde::{Deserialize, Serialize};
+
+/// A request coming in from the client on stdin, already stripped down to
+/// the bits a backend transport needs to know about.
+#[derive(Serialize, Deserialize)]
+pub struct MCPRequest {
+    pub method: String,
+    pub params: serde_json::Value,
+}
+
+// MCP JSON-RPC structures
+#[derive(Serialize, Deserialize, Clone)]
+pub struct JsonRpcRequest {
+    pub jsonrpc: String,
+    pub id: Option<i64>,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+}
+
+impl JsonRpcRequest {
+    pub fn new(id: i64, method: impl Into<String>, params: Option<serde_json::Value>) -> Self {
+        JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(id),
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: String,
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
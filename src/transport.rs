@@ -0,0 +1,291 @@
+// Background-task transport: multiplexes concurrent JSON-RPC requests over a
+// single framed stream (child stdio, a Unix socket, a named pipe, a
+// WebSocket, ...).
+//
+// Construction spawns two Tokio tasks: one drains an outbound channel into
+// the writer half of the stream, the other continuously reads framed
+// messages from the reader half. Responses are correlated back to their
+// caller via a map of pending oneshot senders keyed by request id; anything
+// that arrives without a matching id (server-initiated notifications or
+// requests) is forwarded on a separate channel instead.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::protocol::JsonRpcRequest;
+
+type PendingMap = Arc<Mutex<HashMap<i64, oneshot::Sender<serde_json::Value>>>>;
+
+/// A multiplexing JSON-RPC transport over a framed stream. Safe to share
+/// behind `&self` and call `send_request` from multiple tasks concurrently.
+pub struct Transport {
+    next_id: AtomicI64,
+    pending: PendingMap,
+    outbound_tx: mpsc::UnboundedSender<String>,
+}
+
+impl Transport {
+    /// Spawn the reader/writer tasks over a newline-delimited byte stream
+    /// (child stdio, a Unix socket, a named pipe) and return the transport
+    /// handle along with a channel of server-initiated messages.
+    pub fn spawn<R, W>(reader: R, writer: W) -> (Self, mpsc::UnboundedReceiver<serde_json::Value>)
+    where
+        R: AsyncBufRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        let (pending, outbound_tx, outbound_rx, notification_tx, notification_rx) = Self::channels();
+
+        tokio::spawn(Self::writer_task(writer, outbound_rx));
+        tokio::spawn(Self::reader_task(reader, pending.clone(), notification_tx));
+
+        (Self::new(pending, outbound_tx), notification_rx)
+    }
+
+    /// Spawn the reader/writer tasks over an already message-framed
+    /// stream/sink pair (e.g. a WebSocket's text frames) and return the
+    /// transport handle along with a channel of server-initiated messages.
+    pub fn spawn_framed<S, K>(
+        incoming: S,
+        outgoing: K,
+    ) -> (Self, mpsc::UnboundedReceiver<serde_json::Value>)
+    where
+        S: Stream<Item = Result<String>> + Unpin + Send + 'static,
+        K: Sink<String> + Unpin + Send + 'static,
+    {
+        let (pending, outbound_tx, outbound_rx, notification_tx, notification_rx) = Self::channels();
+
+        tokio::spawn(Self::framed_writer_task(outgoing, outbound_rx));
+        tokio::spawn(Self::framed_reader_task(incoming, pending.clone(), notification_tx));
+
+        (Self::new(pending, outbound_tx), notification_rx)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn channels() -> (
+        PendingMap,
+        mpsc::UnboundedSender<String>,
+        mpsc::UnboundedReceiver<String>,
+        mpsc::UnboundedSender<serde_json::Value>,
+        mpsc::UnboundedReceiver<serde_json::Value>,
+    ) {
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (outbound_tx, outbound_rx) = mpsc::unbounded_channel::<String>();
+        let (notification_tx, notification_rx) = mpsc::unbounded_channel::<serde_json::Value>();
+        (pending, outbound_tx, outbound_rx, notification_tx, notification_rx)
+    }
+
+    fn new(pending: PendingMap, outbound_tx: mpsc::UnboundedSender<String>) -> Self {
+        Transport {
+            next_id: AtomicI64::new(1),
+            pending,
+            outbound_tx,
+        }
+    }
+
+    async fn writer_task<W>(mut writer: W, mut outbound_rx: mpsc::UnboundedReceiver<String>)
+    where
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        while let Some(line) = outbound_rx.recv().await {
+            if writer.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+            if writer.write_all(b"\n").await.is_err() {
+                break;
+            }
+            if writer.flush().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    async fn reader_task<R>(
+        reader: R,
+        pending: PendingMap,
+        notification_tx: mpsc::UnboundedSender<serde_json::Value>,
+    ) where
+        R: AsyncBufRead + Unpin + Send + 'static,
+    {
+        let mut lines = reader.lines();
+        loop {
+            let line = match lines.next_line().await {
+                Ok(Some(line)) => line,
+                Ok(None) => break,
+                Err(_) => break,
+            };
+            Self::dispatch_incoming(&line, &pending, &notification_tx);
+        }
+        Self::close_pending(&pending);
+    }
+
+    async fn framed_writer_task<K>(mut outgoing: K, mut outbound_rx: mpsc::UnboundedReceiver<String>)
+    where
+        K: Sink<String> + Unpin + Send + 'static,
+    {
+        while let Some(message) = outbound_rx.recv().await {
+            if outgoing.send(message).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    async fn framed_reader_task<S>(
+        mut incoming: S,
+        pending: PendingMap,
+        notification_tx: mpsc::UnboundedSender<serde_json::Value>,
+    ) where
+        S: Stream<Item = Result<String>> + Unpin + Send + 'static,
+    {
+        while let Some(item) = incoming.next().await {
+            let message = match item {
+                Ok(message) => message,
+                Err(_) => break,
+            };
+            Self::dispatch_incoming(&message, &pending, &notification_tx);
+        }
+        Self::close_pending(&pending);
+    }
+
+    /// Drop every still-pending oneshot sender so the matching
+    /// `send_request` callers get an error instead of hanging forever once
+    /// the reader task has stopped (backend closed the stream, or errored).
+    fn close_pending(pending: &PendingMap) {
+        pending.lock().unwrap().clear();
+    }
+
+    /// Parse one incoming framed message: complete the pending oneshot if
+    /// its `id` matches an in-flight request, otherwise forward it as a
+    /// server-initiated notification/request.
+    fn dispatch_incoming(
+        message: &str,
+        pending: &PendingMap,
+        notification_tx: &mpsc::UnboundedSender<serde_json::Value>,
+    ) {
+        let message = message.trim();
+        if message.is_empty() {
+            return;
+        }
+
+        let value: serde_json::Value = match serde_json::from_str(message) {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        let id = value.get("id").and_then(|id| id.as_i64());
+        let sender = id.and_then(|id| pending.lock().unwrap().remove(&id));
+
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(value);
+            }
+            None => {
+                let _ = notification_tx.send(value);
+            }
+        }
+    }
+
+    /// Send a request and wait for its correlated response. Safe to call
+    /// concurrently from multiple callers; each gets its own request id and
+    /// its own oneshot.
+    pub async fn send_request(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id, tx);
+
+        let request = JsonRpcRequest::new(id, method, params);
+        let request_json =
+            serde_json::to_string(&request).context("Failed to serialize JSON-RPC request")?;
+
+        if self.outbound_tx.send(request_json).is_err() {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(anyhow::anyhow!("Transport writer task has shut down"));
+        }
+
+        rx.await
+            .context("Transport reader task closed before a response arrived")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_pending() -> PendingMap {
+        Arc::new(Mutex::new(HashMap::new()))
+    }
+
+    #[test]
+    fn dispatch_incoming_completes_the_matching_pending_request() {
+        let pending = new_pending();
+        let (tx, rx) = oneshot::channel();
+        pending.lock().unwrap().insert(1, tx);
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        Transport::dispatch_incoming(
+            r#"{"jsonrpc":"2.0","id":1,"result":{}}"#,
+            &pending,
+            &notification_tx,
+        );
+
+        assert!(pending.lock().unwrap().is_empty());
+        assert_eq!(rx.try_recv().unwrap()["id"], 1);
+        assert!(notification_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn dispatch_incoming_forwards_messages_with_no_matching_id() {
+        let pending = new_pending();
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        // No pending request for id 99, and notifications have no id at all.
+        Transport::dispatch_incoming(
+            r#"{"jsonrpc":"2.0","id":99,"result":{}}"#,
+            &pending,
+            &notification_tx,
+        );
+        Transport::dispatch_incoming(
+            r#"{"jsonrpc":"2.0","method":"notifications/progress","params":{}}"#,
+            &pending,
+            &notification_tx,
+        );
+
+        assert_eq!(notification_rx.try_recv().unwrap()["id"], 99);
+        assert_eq!(
+            notification_rx.try_recv().unwrap()["method"],
+            "notifications/progress"
+        );
+    }
+
+    #[test]
+    fn dispatch_incoming_ignores_blank_and_unparseable_lines() {
+        let pending = new_pending();
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        Transport::dispatch_incoming("   ", &pending, &notification_tx);
+        Transport::dispatch_incoming("not json", &pending, &notification_tx);
+
+        assert!(notification_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn close_pending_drops_senders_so_waiters_get_an_error() {
+        let pending = new_pending();
+        let (tx, rx) = oneshot::channel::<serde_json::Value>();
+        pending.lock().unwrap().insert(1, tx);
+
+        Transport::close_pending(&pending);
+
+        assert!(pending.lock().unwrap().is_empty());
+        assert!(rx.try_recv().is_err());
+    }
+}